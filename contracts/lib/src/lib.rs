@@ -1,10 +1,85 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use ink::prelude::vec::Vec;
-use openbrush::traits::{AccountId, Balance, Timestamp};
-use openbrush::contracts::psp22::PSP22Error;
+//! Interfaces shared between the escrow contract and the contracts it calls or is called by.
+//!
+//! Both sides of a cross-contract call must agree on selectors and on how arguments and errors
+//! are encoded, so the traits and error types used on either side are defined once here.
+
+use ink::prelude::{string::String, vec::Vec};
+use ink::primitives::{Address, H256};
 use scale::{Decode, Encode};
-use scale_info::TypeInfo;
+
+type Balance = u128;
+type Timestamp = u64;
+
+/// Escrow contract error types
+#[derive(Encode, Decode, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum EscrowError {
+    Unauthorized,
+    EscrowNotFound,
+    MilestoneNotFound,
+    InvalidStatus,
+    InvalidEscrowStatus,
+    ContractPaused,
+    InsufficientBalance,
+    TokenTransferFailed,
+    DeadlineExceeded,
+    AlreadyCompleted,
+    InvalidAmount,
+    DuplicateId,
+    FeeTooHigh,
+    TokenNotConfigured,
+    ArithmeticOverflow,
+    StorageLimitExceeded,
+    InvalidTransition,
+    ReviewPeriodActive,
+    AssetMismatch,
+    AmountMismatch,
+    DisputeNotFound,
+    NotEnoughArbiters,
+    AlreadyVoted,
+    AppealWindowClosed,
+    AppealWindowActive,
+    ResponseDeadlineActive,
+    SettlementNotFound,
+    SettlementMismatch,
+    ArbitrationConflict,
+    ArbitratorCallFailed,
+    ContractNotPaused,
+    ProposalExpired,
+    TimelockActive,
+    UpgradeFailed,
+}
+
+/// Arbitrator error types
+#[derive(Encode, Decode, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ArbitratorError {
+    Rejected,
+    Custom(String),
+}
+
+/// Interface of an external arbitration service that escrows can hand their disputes to
+#[ink::trait_definition]
+pub trait Arbitrator {
+    /// Registers a dispute opened on the calling escrow contract; the service later
+    /// delivers its ruling through `Arbitrable::rule`
+    #[ink(message)]
+    fn create_dispute(
+        &mut self,
+        dispute_id: u64,
+        escrow_id: String,
+        amount: u128,
+    ) -> Result<(), ArbitratorError>;
+}
+
+/// Callback through which an arbitration service delivers its ruling
+#[ink::trait_definition]
+pub trait Arbitrable {
+    #[ink(message)]
+    fn rule(&mut self, dispute_id: u64, payee_bps: u16) -> Result<(), EscrowError>;
+}
 
 /// The status of an escrow.
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
@@ -31,7 +106,8 @@ pub enum MilestoneStatus {
 }
 
 /// Types of conditions that can trigger automatic milestone release.
-#[derive(Debug, PartialEq, Eq, Encode, Decode, TypeInfo, Clone)]
+#[derive(Debug, PartialEq, Eq, Encode, Decode, Clone)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum ReleaseConditionType {
     /// Condition verified by a specific third-party account
     ThirdPartyVerification,
@@ -53,7 +129,7 @@ pub struct ReleaseCondition {
     /// When the condition was verified (if applicable)
     pub verified_at: Option<Timestamp>,
     /// Address of the verifier (if applicable)
-    pub verified_by: Option<AccountId>,
+    pub verified_by: Option<Address>,
 }
 
 /// Proposal for modifying a milestone (shared between contract and client code).
@@ -88,58 +164,8 @@ pub struct Milestone {
     pub conditions: Option<Vec<ReleaseCondition>>,
 }
 
-/// Errors that can occur during escrow operations.
-#[derive(Debug, PartialEq, Eq, Encode, Decode)]
-pub enum EscrowError {
-    /// An error occurred in the PSP22 token operations.
-    PSP22Error(PSP22Error),
-    /// Caller is not authorized to perform this action.
-    NotAuthorized,
-    /// The escrow does not exist.
-    EscrowNotFound,
-    /// The milestone does not exist.
-    MilestoneNotFound,
-    /// The escrow is not in the required status.
-    InvalidEscrowStatus,
-    /// The milestone is not in the required status.
-    InvalidMilestoneStatus,
-    /// The milestone percentages do not add up to 100%.
-    InvalidMilestones,
-    /// The escrow amount is invalid.
-    InvalidAmount,
-    /// Cannot perform this action on an escrow in dispute.
-    InDispute,
-    /// A deadline has passed.
-    DeadlinePassed,
-    /// The percentage is invalid (must be between 1 and 100).
-    InvalidPercentage,
-    /// The provided signature is invalid.
-    InvalidSignature,
-    /// The operation requires multi-signature approval.
-    RequiresMultiSignature,
-    /// The transaction limit has been exceeded.
-    TransactionLimitExceeded,
-    /// The contract is paused.
-    ContractPaused,
-    /// Operation would cause a reentrancy.
-    ReentrancyGuard,
-    /// Upgrade error.
-    UpgradeError,
-    /// Custom error with a message.
-    Custom(Vec<u8>),
-}
-
-impl From<PSP22Error> for EscrowError {
-    fn from(error: PSP22Error) -> Self {
-        EscrowError::PSP22Error(error)
-    }
-}
-
-impl From<openbrush::contracts::ownable::OwnableError> for EscrowError {
-    fn from(_: openbrush::contracts::ownable::OwnableError) -> Self {
-        EscrowError::NotAuthorized
-    }
-}
+/// A milestone as passed to `Escrow::create_escrow`: (title, description, percentage, deadline)
+pub type MilestoneSpec = (Vec<u8>, Vec<u8>, u8, Option<Timestamp>);
 
 /// Events emitted by the escrow contract (not stored in mappings)
 #[derive(Debug, Encode, Decode)]
@@ -147,10 +173,10 @@ pub enum EscrowEvent {
     /// A new escrow was created
     EscrowCreated {
         escrow_id: u32,
-        client: AccountId,
-        provider: AccountId,
+        client: Address,
+        provider: Address,
         amount: Balance,
-        token: AccountId,
+        token: Address,
         milestones_count: u32,
     },
     /// Funds were released for a milestone
@@ -158,27 +184,27 @@ pub enum EscrowEvent {
         escrow_id: u32,
         milestone_id: u32,
         amount: Balance,
-        provider: AccountId,
+        provider: Address,
         fee: Balance,
     },
     /// A milestone was marked as completed by the provider
     MilestoneCompleted {
         escrow_id: u32,
         milestone_id: u32,
-        provider: AccountId,
+        provider: Address,
     },
     /// Evidence was added for a milestone
     EvidenceAdded {
         escrow_id: u32,
         milestone_id: u32,
         evidence_hash: Vec<u8>,
-        provider: AccountId,
+        provider: Address,
     },
     /// An escrow was cancelled
     EscrowCancelled {
         escrow_id: u32,
-        client: AccountId,
-        provider: AccountId,
+        client: Address,
+        provider: Address,
         remaining_amount: Balance,
     },
     /// A dispute was created
@@ -186,7 +212,7 @@ pub enum EscrowEvent {
         dispute_id: u32,
         escrow_id: u32,
         milestone_id: Option<u32>,
-        initiator: AccountId,
+        initiator: Address,
     },
     /// A dispute was resolved
     DisputeResolved {
@@ -197,57 +223,57 @@ pub enum EscrowEvent {
     },
     /// The contract was paused
     ContractPaused {
-        paused_by: AccountId,
+        paused_by: Address,
     },
     /// The contract was unpaused
     ContractUnpaused {
-        unpaused_by: AccountId,
+        unpaused_by: Address,
     },
     /// A transaction limit was updated
     TransactionLimitUpdated {
         new_limit: Balance,
-        updated_by: AccountId,
+        updated_by: Address,
     },
     /// The fee percentage was updated
     FeeUpdated {
         new_fee_bps: u16,
-        updated_by: AccountId,
+        updated_by: Address,
     },
     /// The fee account was updated
     FeeAccountUpdated {
-        new_fee_account: AccountId,
-        updated_by: AccountId,
+        new_fee_account: Address,
+        updated_by: Address,
     },
     /// The contract was upgraded
     ContractUpgraded {
-        old_code_hash: [u8; 32],
-        new_code_hash: [u8; 32],
-        upgraded_by: AccountId,
+        old_code_hash: H256,
+        new_code_hash: H256,
+        upgraded_by: Address,
     },
 }
 
 /// Interface for contract upgrades
-#[openbrush::trait_definition]
+#[ink::trait_definition]
 pub trait Upgradeable {
     /// Upgrades the contract to a new code hash
     #[ink(message)]
-    fn upgrade(&mut self, new_code_hash: [u8; 32]) -> Result<(), EscrowError>;
+    fn upgrade(&mut self, new_code_hash: H256) -> Result<(), EscrowError>;
     /// Gets the current code hash of the contract
     #[ink(message)]
-    fn get_code_hash(&self) -> [u8; 32];
+    fn get_code_hash(&self) -> H256;
 }
 
 /// Interface for the escrow contract.
-#[openbrush::trait_definition]
+#[ink::trait_definition]
 pub trait Escrow {
     /// Creates a new escrow between a client and provider.
     #[ink(message)]
     fn create_escrow(
         &mut self,
-        provider: AccountId,
+        provider: Address,
         amount: Balance,
-        milestones: Vec<(Vec<u8>, Vec<u8>, u8, Option<Timestamp>)>,
-        token_address: AccountId,
+        milestones: Vec<MilestoneSpec>,
+        token_address: Address,
     ) -> Result<(), EscrowError>;
 
     /// Releases funds for a completed milestone.
@@ -302,7 +328,7 @@ pub trait Escrow {
     #[ink(message)]
     fn get_user_escrows(
         &self,
-        user: AccountId,
+        user: Address,
     ) -> Vec<u32>;
 
     /// Emergency withdraw when contract is paused (admin only)
@@ -310,6 +336,6 @@ pub trait Escrow {
     fn emergency_withdraw(
         &mut self,
         escrow_id: u32,
-        recipient: AccountId,
+        recipient: Address,
     ) -> Result<(), EscrowError>;
-}
\ No newline at end of file
+}
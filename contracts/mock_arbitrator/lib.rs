@@ -0,0 +1,387 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![allow(unexpected_cfgs)]
+
+/// Minimal `Arbitrator` implementation for exercising escrow dispute forwarding.
+///
+/// Escrow contracts register disputes with `Arbitrator::create_dispute`; the owner then
+/// decides them with `give_ruling`, which calls back into the escrow's `Arbitrable::rule`.
+#[ink::contract]
+mod mock_arbitrator {
+    use escrow_lib::{Arbitrator, ArbitratorError, EscrowError};
+    use ink::storage::Mapping;
+    use ink::prelude::string::String;
+    use scale::{Decode, Encode};
+
+    /// Errors returned by the mock's own messages
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        NotOwner,
+        DisputeNotFound,
+        AlreadyRuled,
+        InvalidRuling,
+        /// The escrow contract rejected the ruling
+        RulingRejected(EscrowError),
+        /// The call into the escrow contract failed before it could answer
+        EscrowCallFailed,
+    }
+
+    /// A dispute registered by an escrow contract
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ForwardedDispute {
+        pub escrow_id: String,
+        pub amount: Balance,
+        pub ruling: Option<u16>,
+    }
+
+    /// Storage for the mock arbitrator
+    #[ink(storage)]
+    pub struct MockArbitrator {
+        owner: Address,
+        /// Disputes keyed by (escrow contract, escrow-side dispute ID)
+        disputes: Mapping<(Address, u64), ForwardedDispute>,
+    }
+
+    impl MockArbitrator {
+        /// Creates the arbitrator with the caller as the only account allowed to rule
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                disputes: Mapping::new(),
+            }
+        }
+
+        /// Returns a dispute registered by `escrow_contract`
+        #[ink(message)]
+        pub fn get_dispute(&self, escrow_contract: Address, dispute_id: u64) -> Option<ForwardedDispute> {
+            self.disputes.get((escrow_contract, dispute_id))
+        }
+
+        /// Rules on a registered dispute and delivers the ruling to its escrow contract
+        #[ink(message)]
+        pub fn give_ruling(
+            &mut self,
+            escrow_contract: Address,
+            dispute_id: u64,
+            payee_bps: u16,
+        ) -> core::result::Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            if payee_bps > 10_000 {
+                return Err(Error::InvalidRuling);
+            }
+
+            let mut dispute = self
+                .disputes
+                .get((escrow_contract, dispute_id))
+                .ok_or(Error::DisputeNotFound)?;
+            if dispute.ruling.is_some() {
+                return Err(Error::AlreadyRuled);
+            }
+
+            dispute.ruling = Some(payee_bps);
+            self.disputes.insert((escrow_contract, dispute_id), &dispute);
+
+            self.escrow_rule(escrow_contract, dispute_id, payee_bps)
+        }
+
+        /// Helper function to call `Arbitrable::rule` on an escrow contract
+        fn escrow_rule(
+            &self,
+            escrow_contract: Address,
+            dispute_id: u64,
+            payee_bps: u16,
+        ) -> core::result::Result<(), Error> {
+            use ink::env::call::{build_call, ExecutionInput, Selector};
+
+            match build_call::<ink::env::DefaultEnvironment>()
+                .call(escrow_contract)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("Arbitrable::rule")))
+                        .push_arg(dispute_id)
+                        .push_arg(payee_bps)
+                )
+                .returns::<core::result::Result<(), EscrowError>>()
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(err))) => Err(Error::RulingRejected(err)),
+                _ => Err(Error::EscrowCallFailed),
+            }
+        }
+    }
+
+    impl Arbitrator for MockArbitrator {
+        /// Registers a dispute for the calling escrow contract
+        #[ink(message)]
+        fn create_dispute(
+            &mut self,
+            dispute_id: u64,
+            escrow_id: String,
+            amount: Balance,
+        ) -> core::result::Result<(), ArbitratorError> {
+            let escrow_contract = self.env().caller();
+            if self.disputes.contains((escrow_contract, dispute_id)) {
+                return Err(ArbitratorError::Rejected);
+            }
+
+            self.disputes.insert(
+                (escrow_contract, dispute_id),
+                &ForwardedDispute {
+                    escrow_id,
+                    amount,
+                    ruling: None,
+                },
+            );
+            Ok(())
+        }
+    }
+
+    impl Default for MockArbitrator {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use ink::prelude::string::ToString;
+
+        #[ink::test]
+        fn test_create_dispute_and_ruling_guards() {
+            let accounts = test::default_accounts();
+            test::set_caller(accounts.alice);
+            let mut arbitrator = MockArbitrator::new();
+
+            // The escrow contract registers a dispute
+            test::set_caller(accounts.django);
+            arbitrator
+                .create_dispute(1, "escrow_1".to_string(), 100)
+                .unwrap();
+            assert_eq!(
+                arbitrator.create_dispute(1, "escrow_1".to_string(), 100),
+                Err(ArbitratorError::Rejected)
+            );
+            assert_eq!(
+                arbitrator.get_dispute(accounts.django, 1).unwrap().amount,
+                100
+            );
+
+            // Only the owner rules, on known disputes, with a valid split
+            assert_eq!(
+                arbitrator.give_ruling(accounts.django, 1, 5_000),
+                Err(Error::NotOwner)
+            );
+            test::set_caller(accounts.alice);
+            assert_eq!(
+                arbitrator.give_ruling(accounts.django, 2, 5_000),
+                Err(Error::DisputeNotFound)
+            );
+            assert_eq!(
+                arbitrator.give_ruling(accounts.django, 1, 10_001),
+                Err(Error::InvalidRuling)
+            );
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use escrow_contract::{DisputeStatus, EscrowContract, EscrowContractRef, MilestoneInput, PaymentAsset};
+        use ink::prelude::string::ToString;
+        use ink_e2e::{ContractsBackend, IntoAddress};
+        use psp22_token::{Psp22Token, Psp22TokenRef};
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn test_forwarded_dispute_ruled_through_escrow_callback<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // Alice owns the token supply, the arbitrator and the escrow contract
+            let mut token_constructor = Psp22TokenRef::new(1_000_000_000, None, None, 6);
+            let token = client
+                .instantiate("psp22_token", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("token instantiate failed");
+            let mut token_call = token.call_builder::<Psp22Token>();
+
+            let mut arbitrator_constructor = MockArbitratorRef::new();
+            let arbitrator = client
+                .instantiate("mock_arbitrator", &ink_e2e::alice(), &mut arbitrator_constructor)
+                .submit()
+                .await
+                .expect("arbitrator instantiate failed");
+            let mut arbitrator_call = arbitrator.call_builder::<MockArbitrator>();
+
+            let mut escrow_constructor = EscrowContractRef::new(token.addr, ink_e2e::charlie().address());
+            let escrow = client
+                .instantiate("escrow_contract", &ink_e2e::alice(), &mut escrow_constructor)
+                .submit()
+                .await
+                .expect("escrow instantiate failed");
+            let mut escrow_call = escrow.call_builder::<EscrowContract>();
+
+            let milestones = vec![MilestoneInput {
+                id: "m1".to_string(),
+                description: "Task".to_string(),
+                amount: "1".to_string(),
+                status: "Pending".to_string(),
+                deadline: u64::MAX,
+                completed_at: None,
+                dispute_reason: None,
+                dispute_filed_by: None,
+                completion_note: None,
+                evidence_file: None,
+            }];
+
+            // Two funded escrows: one names the mock as arbitrator, the other a contract that
+            // does not implement `Arbitrator`
+            let mut escrow_ids = Vec::new();
+            for arbitrator_contract in [arbitrator.addr, token.addr] {
+                let create = escrow_call.create_escrow(
+                    ink_e2e::bob().address(),
+                    "provider".to_string(),
+                    "Test".to_string(),
+                    "Desc".to_string(),
+                    "1".to_string(),
+                    milestones.clone(),
+                    None,
+                    PaymentAsset::Psp22(token.addr),
+                    None,
+                    Some(arbitrator_contract),
+                );
+                let escrow_id = client
+                    .call(&ink_e2e::alice(), &create)
+                    .submit()
+                    .await
+                    .expect("create_escrow failed")
+                    .return_value()
+                    .expect("create_escrow rejected");
+
+                let approve = token_call.approve(escrow.addr, 1_000_000);
+                client
+                    .call(&ink_e2e::alice(), &approve)
+                    .submit()
+                    .await
+                    .expect("approve failed");
+                let fund = escrow_call.fund_escrow(escrow_id.clone(), 1_000_000);
+                client
+                    .call(&ink_e2e::alice(), &fund)
+                    .submit()
+                    .await
+                    .expect("fund_escrow failed");
+
+                let accept = escrow_call.accept_escrow(escrow_id.clone());
+                client
+                    .call(&ink_e2e::bob(), &accept)
+                    .submit()
+                    .await
+                    .expect("accept_escrow failed");
+                let get_milestone = escrow_call.get_escrow_milestone(escrow_id.clone(), "m1".to_string());
+                let milestone = client
+                    .call(&ink_e2e::bob(), &get_milestone)
+                    .dry_run()
+                    .await?
+                    .return_value()
+                    .expect("milestone not found");
+                let start = escrow_call.update_escrow_milestone_status(
+                    escrow_id.clone(),
+                    milestone,
+                    "InProgress".to_string(),
+                );
+                client
+                    .call(&ink_e2e::bob(), &start)
+                    .submit()
+                    .await
+                    .expect("starting the milestone failed");
+
+                escrow_ids.push(escrow_id);
+            }
+
+            // Disputing forwards the dispute to the mock arbitrator
+            let dispute = escrow_call.dispute_milestone(
+                escrow_ids[0].clone(),
+                "m1".to_string(),
+                "Late".to_string(),
+            );
+            let dispute_id = client
+                .call(&ink_e2e::bob(), &dispute)
+                .submit()
+                .await
+                .expect("dispute_milestone failed")
+                .return_value()
+                .expect("dispute_milestone rejected")
+                .dispute_id;
+
+            let get_dispute = arbitrator_call.get_dispute(escrow.addr, dispute_id);
+            let forwarded = client
+                .call(&ink_e2e::alice(), &get_dispute)
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("dispute was not forwarded");
+            assert_eq!(forwarded.escrow_id, escrow_ids[0]);
+            assert_eq!(forwarded.amount, 1_000_000);
+
+            // A contract that cannot take the dispute makes the escrow refuse it
+            let dispute = escrow_call.dispute_milestone(
+                escrow_ids[1].clone(),
+                "m1".to_string(),
+                "Late".to_string(),
+            );
+            let result = client
+                .call(&ink_e2e::bob(), &dispute)
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result.map(|r| r.dispute_id), Err(EscrowError::ArbitratorCallFailed));
+
+            // The ruling comes back through `Arbitrable::rule` and pays out the escrow
+            let give_ruling = arbitrator_call.give_ruling(escrow.addr, dispute_id, 10_000);
+            client
+                .call(&ink_e2e::alice(), &give_ruling)
+                .submit()
+                .await
+                .expect("give_ruling failed")
+                .return_value()
+                .expect("ruling rejected");
+
+            let bob_balance = token_call.balance_of(ink_e2e::bob().address());
+            let balance = client
+                .call(&ink_e2e::alice(), &bob_balance)
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(balance, 990_000);
+
+            let get_escrow_dispute = escrow_call.get_dispute(dispute_id);
+            let resolved = client
+                .call(&ink_e2e::alice(), &get_escrow_dispute)
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("dispute not found");
+            assert_eq!(resolved.status, DisputeStatus::Resolved);
+            assert_eq!(resolved.outcome.expect("no outcome").resolved_by, arbitrator.addr);
+
+            // Each forwarded dispute is ruled on once
+            let rule_again = arbitrator_call.give_ruling(escrow.addr, dispute_id, 0);
+            let result = client
+                .call(&ink_e2e::alice(), &rule_again)
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(result, Err(Error::AlreadyRuled));
+
+            Ok(())
+        }
+    }
+}
@@ -267,4 +267,5 @@ mod psp22_token {
             Ok(())
         }
     }
-}
\ No newline at end of file
+}
+pub use self::psp22_token::Psp22Token;